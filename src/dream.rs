@@ -116,7 +116,7 @@ fn mitosis(dreams: &[Dream], i: usize) -> Vec<Dream> {
     res
 }
 
-fn lex_first_reduced_word(p: &Perm) -> Vec<usize> {
+pub(crate) fn lex_first_reduced_word(p: &Perm) -> Vec<usize> {
     let c = p.lehmer();
     let mut word = Vec::new();
     for (i, ci) in c.iter().rev().enumerate() {
@@ -130,7 +130,9 @@ fn reduced_dreams(p: &Perm) -> Vec<Dream> {
     let mut reduced = lex_first_reduced_word(&p0p);
 
     if reduced.is_empty() {
-        return Vec::new();
+        // `p0p` is the identity, i.e. `p` is already `Perm::long`: zero
+        // mitosis steps are needed, so the long dream is the only one.
+        return vec![Dream::long(p.len())];
     }
 
     reduced.reverse();
@@ -1,6 +1,6 @@
 use std::{
     fmt,
-    ops::{Index, IndexMut},
+    ops::{Add, Index, IndexMut, Mul, Neg, Sub},
 };
 
 /// A square matrix with a flat vector representation.
@@ -81,13 +81,33 @@ impl<T: Copy + Default> SqMat<T> {
     /// Convert a `SqMat<T>` to a `SqMat<S>` by applying `f` to the entries.
     pub fn map<S: Default + Copy>(&self, f: impl Fn(T) -> S) -> SqMat<S> {
         let mut res = SqMat::new(self.dim());
-        for i in 0..self.dim() {
-            for j in 0..self.dim() {
-                res[(i, j)] = f(self[(i, j)]);
-            }
+        for (i, j) in self.indices() {
+            res[(i, j)] = f(self[(i, j)]);
         }
         res
     }
+
+    /// Transpose the matrix.
+    pub fn transpose(&self) -> SqMat<T> {
+        let mut res = SqMat::new(self.dim());
+        for (i, j) in self.indices() {
+            res[(j, i)] = self[(i, j)];
+        }
+        res
+    }
+}
+
+impl<T> SqMat<T> {
+    /// Iterator over every `(row, col)` index pair, in row-major order.
+    pub fn indices(&self) -> impl Iterator<Item = (usize, usize)> {
+        let dim = self.dim;
+        (0..dim).flat_map(move |i| (0..dim).map(move |j| (i, j)))
+    }
+
+    /// Iterator pairing each `(row, col)` index with a reference to its entry.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.indices().map(move |(i, j)| ((i, j), &self[(i, j)]))
+    }
 }
 
 impl<T> Index<usize> for SqMat<T> {
@@ -124,6 +144,172 @@ impl<T> IndexMut<(usize, usize)> for SqMat<T> {
     }
 }
 
+impl<'a, T: Copy + Default + Add<Output = T> + Mul<Output = T>> Mul for &'a SqMat<T> {
+    type Output = SqMat<T>;
+
+    /// Standard O(n^3) matrix multiplication.
+    ///
+    /// Note for `Perm::matrix()`: under this crate's row-vector convention
+    /// (`m[(i, self[i])] = 1`), composition reverses order under the matrix
+    /// product, i.e. `(p * q).matrix() == &q.matrix() * &p.matrix()`, not
+    /// `&p.matrix() * &q.matrix()`.
+    ///
+    /// # Panics
+    ///
+    /// If `self.dim() != rhs.dim()`.
+    fn mul(self, rhs: &'a SqMat<T>) -> Self::Output {
+        assert_eq!(self.dim(), rhs.dim());
+        let dim = self.dim();
+        let mut res = SqMat::new(dim);
+        for i in 0..dim {
+            for j in 0..dim {
+                let mut sum = T::default();
+                for k in 0..dim {
+                    sum = sum + self[(i, k)] * rhs[(k, j)];
+                }
+                res[(i, j)] = sum;
+            }
+        }
+        res
+    }
+}
+
+impl<'a, T: Copy + Default + Add<Output = T>> Add for &'a SqMat<T> {
+    type Output = SqMat<T>;
+
+    /// Entrywise addition.
+    ///
+    /// # Panics
+    ///
+    /// If `self.dim() != rhs.dim()`.
+    fn add(self, rhs: &'a SqMat<T>) -> Self::Output {
+        assert_eq!(self.dim(), rhs.dim());
+        let mut res = SqMat::new(self.dim());
+        for i in 0..self.dim() {
+            for j in 0..self.dim() {
+                res[(i, j)] = self[(i, j)] + rhs[(i, j)];
+            }
+        }
+        res
+    }
+}
+
+impl<'a, T: Copy + Default + Sub<Output = T>> Sub for &'a SqMat<T> {
+    type Output = SqMat<T>;
+
+    /// Entrywise subtraction.
+    ///
+    /// # Panics
+    ///
+    /// If `self.dim() != rhs.dim()`.
+    fn sub(self, rhs: &'a SqMat<T>) -> Self::Output {
+        assert_eq!(self.dim(), rhs.dim());
+        let mut res = SqMat::new(self.dim());
+        for i in 0..self.dim() {
+            for j in 0..self.dim() {
+                res[(i, j)] = self[(i, j)] - rhs[(i, j)];
+            }
+        }
+        res
+    }
+}
+
+impl<T: Copy + Default + Neg<Output = T>> Neg for &SqMat<T> {
+    type Output = SqMat<T>;
+
+    /// Entrywise negation.
+    fn neg(self) -> Self::Output {
+        let mut res = SqMat::new(self.dim());
+        for i in 0..self.dim() {
+            for j in 0..self.dim() {
+                res[(i, j)] = -self[(i, j)];
+            }
+        }
+        res
+    }
+}
+
+impl<
+        T: Copy
+            + Default
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Neg<Output = T>
+            + Mul<Output = T>
+            + From<u8>,
+    > SqMat<T>
+{
+    /// Returns the minor matrix obtained by deleting row `row` and column `col`.
+    ///
+    /// # Panics
+    ///
+    /// If `row >= self.dim()` or `col >= self.dim()`.
+    pub fn minor(&self, row: usize, col: usize) -> SqMat<T> {
+        assert!(row < self.dim() && col < self.dim());
+        let mut res = SqMat::new(self.dim() - 1);
+        for i in 0..self.dim() {
+            if i == row {
+                continue;
+            }
+            let ri = if i < row { i } else { i - 1 };
+            for j in 0..self.dim() {
+                if j == col {
+                    continue;
+                }
+                let rj = if j < col { j } else { j - 1 };
+                res[(ri, rj)] = self[(i, j)];
+            }
+        }
+        res
+    }
+
+    /// Compute the determinant via Laplace expansion along the first row.
+    ///
+    /// `T` must be a signed type, since intermediate terms can go negative
+    /// even when the final determinant doesn't (e.g. permutation matrices
+    /// have determinant `±1`). `Perm::matrix()` returns `SqMat<u8>`, so
+    /// convert it first, e.g. `p.matrix().map(|x| x as i64).determinant()`.
+    pub fn determinant(&self) -> T {
+        match self.dim() {
+            0 => T::from(1),
+            1 => self[(0, 0)],
+            _ => {
+                let mut sum = T::default();
+                for j in 0..self.dim() {
+                    let term = self[(0, j)] * self.minor(0, j).determinant();
+                    sum = if j % 2 == 0 { sum + term } else { sum - term };
+                }
+                sum
+            }
+        }
+    }
+}
+
+impl<T: Copy + Default + Add<Output = T> + Mul<Output = T> + From<u8>> SqMat<T> {
+    /// Create the `dim`-by-`dim` identity matrix.
+    pub fn identity(dim: usize) -> Self {
+        let mut res = Self::new(dim);
+        for i in 0..dim {
+            res[(i, i)] = T::from(1);
+        }
+        res
+    }
+
+    /// Raise the matrix to the `e`-th power by exponentiation by squaring.
+    pub fn pow(&self, mut e: u64) -> Self {
+        let mut acc = Self::identity(self.dim());
+        let mut base = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                acc = &acc * &base;
+            }
+            base = &base * &base;
+            e >>= 1;
+        }
+        acc
+    }
+}
+
 impl<T: Copy + Default + fmt::Display> fmt::Display for SqMat<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for row in self.rows() {
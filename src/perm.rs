@@ -102,11 +102,9 @@ impl Perm {
 
     pub fn rothe(&self) -> SqMat<u8> {
         let mut rothe = SqMat::new(self.len());
-        for i in 0..self.len() {
-            for j in 0..self.len() {
-                if i < j && self[i] > self[j] {
-                    rothe[(i, self[j])] = 1;
-                }
+        for (i, j) in rothe.indices() {
+            if i < j && self[i] > self[j] {
+                rothe[(i, self[j])] = 1;
             }
         }
         rothe
@@ -1,7 +1,11 @@
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    ops::{Add, Mul, Sub},
+};
 
 use crate::{
-    dream::{Dream, ReducedDreams, Tile},
+    dream::{lex_first_reduced_word, Dream, ReducedDreams, Tile},
     perm::Perm,
 };
 
@@ -20,12 +24,11 @@ impl Monomial {
 
 impl<'a> From<&'a Dream> for Monomial {
     fn from(dream: &'a Dream) -> Self {
+        let tiles = dream.tiles();
         let mut powers = HashMap::new();
-        for i in 0..dream.dim() {
-            for j in 0..dream.dim() {
-                if dream[(i, j)] == Tile::Cross {
-                    *powers.entry(i).or_insert(0) += 1;
-                }
+        for (i, j) in tiles.indices() {
+            if tiles[(i, j)] == Tile::Cross {
+                *powers.entry(i).or_insert(0) += 1;
             }
         }
         let mut powers: Vec<_> = powers.into_iter().collect();
@@ -79,6 +82,36 @@ impl Schubert {
         }
     }
 
+    /// Compute the Schubert polynomial by applying divided-difference
+    /// operators to the dominant monomial, as an independent alternative
+    /// to the mitosis-based [`Schubert::from_dreams`].
+    pub fn from_divided_differences(perm: &Perm) -> Self {
+        let n = perm.len();
+        let mut word = lex_first_reduced_word(&(perm * &Perm::long(n)));
+        word.reverse();
+
+        let mut f = dominant_poly(n);
+        for &i in &word {
+            f = divided_difference(&f, i);
+        }
+
+        // Each term's coefficient is the number of times its monomial
+        // occurs in `parts` (see `Schubert::from_dreams`'s convention);
+        // Schubert polynomials have non-negative coefficients.
+        let parts = f
+            .terms()
+            .flat_map(|(exp, coeff)| {
+                assert!(coeff > 0, "Schubert polynomials have positive coefficients");
+                std::iter::repeat_with(move || monomial_from_exp(exp)).take(coeff as usize)
+            })
+            .collect();
+
+        Self {
+            perm: perm.clone(),
+            parts,
+        }
+    }
+
     pub fn perm(&self) -> &Perm {
         &self.perm
     }
@@ -86,6 +119,116 @@ impl Schubert {
     pub fn parts(&self) -> &[Monomial] {
         &self.parts
     }
+
+    /// Evaluate the Schubert polynomial at `x`, substituting `x[i]` for
+    /// each `x_i`.
+    ///
+    /// # Panics
+    ///
+    /// If a monomial references a variable index `>= x.len()`.
+    pub fn evaluate(&self, x: &[i64]) -> i64 {
+        // An empty `parts` is the constant polynomial `1`.
+        if self.parts().is_empty() {
+            return 1;
+        }
+        self.parts()
+            .iter()
+            .map(|mono| {
+                mono.powers()
+                    .iter()
+                    .map(|&(i, p)| pow(x[i], p as u64))
+                    .product::<i64>()
+            })
+            .sum()
+    }
+
+    /// Evaluate the Schubert polynomial at `x` modulo the prime `m`.
+    ///
+    /// # Panics
+    ///
+    /// If a monomial references a variable index `>= x.len()`.
+    pub fn evaluate_mod(&self, x: &[i64], m: i64) -> i64 {
+        // An empty `parts` is the constant polynomial `1`.
+        if self.parts().is_empty() {
+            return ModInt::new(1, m).value();
+        }
+        let x: Vec<ModInt> = x.iter().map(|&v| ModInt::new(v, m)).collect();
+        self.parts()
+            .iter()
+            .fold(ModInt::new(0, m), |acc, mono| {
+                let term = mono
+                    .powers()
+                    .iter()
+                    .fold(ModInt::new(1, m), |p, &(i, e)| p * x[i].pow(e as u64));
+                acc + term
+            })
+            .value()
+    }
+}
+
+/// Integer exponentiation by squaring.
+fn pow(mut base: i64, mut e: u64) -> i64 {
+    let mut res = 1;
+    while e > 0 {
+        if e & 1 == 1 {
+            res *= base;
+        }
+        base *= base;
+        e >>= 1;
+    }
+    res
+}
+
+/// An integer reduced modulo a prime `m`.
+#[derive(Clone, Copy, Debug)]
+pub struct ModInt {
+    value: i64,
+    modulus: i64,
+}
+
+impl ModInt {
+    /// Create a `ModInt` from `value`, reduced modulo `modulus`.
+    pub fn new(value: i64, modulus: i64) -> Self {
+        Self {
+            value: value.rem_euclid(modulus),
+            modulus,
+        }
+    }
+
+    /// The reduced value in `0..modulus`.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Raise to the `e`-th power by exponentiation by squaring.
+    pub fn pow(&self, mut e: u64) -> Self {
+        let mut base = *self;
+        let mut res = ModInt::new(1, self.modulus);
+        while e > 0 {
+            if e & 1 == 1 {
+                res = res * base;
+            }
+            base = base * base;
+            e >>= 1;
+        }
+        res
+    }
+}
+
+impl Add for ModInt {
+    type Output = ModInt;
+
+    fn add(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.value + rhs.value, self.modulus)
+    }
+}
+
+impl Mul for ModInt {
+    type Output = ModInt;
+
+    fn mul(self, rhs: ModInt) -> ModInt {
+        ModInt::new(self.value * rhs.value, self.modulus)
+    }
 }
 
 impl fmt::Display for Schubert {
@@ -102,3 +245,213 @@ impl fmt::Display for Schubert {
         Ok(())
     }
 }
+
+/// Drop any trailing zero exponents so that exponent vectors are a
+/// canonical key, independent of the number of variables used to build them.
+fn trim(mut exp: Vec<usize>) -> Vec<usize> {
+    while exp.last() == Some(&0) {
+        exp.pop();
+    }
+    exp
+}
+
+/// Multivariate polynomial with integer coefficients, stored as a sorted
+/// map from exponent vectors (one exponent per variable, trailing zeros
+/// trimmed) to their coefficients.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Poly {
+    terms: BTreeMap<Vec<usize>, i64>,
+}
+
+impl Poly {
+    /// The zero polynomial.
+    pub fn zero() -> Self {
+        Self {
+            terms: BTreeMap::new(),
+        }
+    }
+
+    /// A single term `coeff * prod x_i^{exp[i]}`.
+    pub fn term(exp: Vec<usize>, coeff: i64) -> Self {
+        let mut terms = BTreeMap::new();
+        if coeff != 0 {
+            terms.insert(trim(exp), coeff);
+        }
+        Self { terms }
+    }
+
+    /// Iterator over `(exponents, coefficient)` pairs.
+    pub fn terms(&self) -> impl Iterator<Item = (&[usize], i64)> {
+        self.terms.iter().map(|(exp, &coeff)| (exp.as_slice(), coeff))
+    }
+}
+
+impl<'a> From<&'a Schubert> for Poly {
+    fn from(schubert: &'a Schubert) -> Self {
+        // An empty `parts` means the constant polynomial `1` (see
+        // `Schubert`'s `Display` impl), not the zero polynomial.
+        if schubert.parts().is_empty() {
+            return Poly::term(vec![], 1);
+        }
+
+        let mut terms = BTreeMap::new();
+        for part in schubert.parts() {
+            let exp = trim(exp_vec(part));
+            *terms.entry(exp).or_insert(0) += 1;
+        }
+        terms.retain(|_, &mut coeff| coeff != 0);
+        Self { terms }
+    }
+}
+
+/// Build a dense exponent vector from a `Monomial`'s sparse `(i, p)` pairs.
+fn exp_vec(mono: &Monomial) -> Vec<usize> {
+    let len = mono.powers().last().map_or(0, |&(i, _)| i + 1);
+    let mut exp = vec![0; len];
+    for &(i, p) in mono.powers() {
+        exp[i] = p;
+    }
+    exp
+}
+
+impl<'a> Add for &'a Poly {
+    type Output = Poly;
+
+    /// Add two polynomials, merging like terms and dropping zero coefficients.
+    fn add(self, rhs: &'a Poly) -> Poly {
+        let mut terms = self.terms.clone();
+        for (exp, &coeff) in &rhs.terms {
+            *terms.entry(exp.clone()).or_insert(0) += coeff;
+        }
+        terms.retain(|_, &mut coeff| coeff != 0);
+        Poly { terms }
+    }
+}
+
+impl<'a> Sub for &'a Poly {
+    type Output = Poly;
+
+    /// Subtract two polynomials, merging like terms and dropping zero coefficients.
+    fn sub(self, rhs: &'a Poly) -> Poly {
+        let mut terms = self.terms.clone();
+        for (exp, &coeff) in &rhs.terms {
+            *terms.entry(exp.clone()).or_insert(0) -= coeff;
+        }
+        terms.retain(|_, &mut coeff| coeff != 0);
+        Poly { terms }
+    }
+}
+
+impl<'a> Mul for &'a Poly {
+    type Output = Poly;
+
+    /// Multiply two polynomials by convolving their monomials: each pair of
+    /// terms multiplies coefficients and sums exponents per variable index.
+    fn mul(self, rhs: &'a Poly) -> Poly {
+        let mut terms: BTreeMap<Vec<usize>, i64> = BTreeMap::new();
+        for (expa, &coeffa) in &self.terms {
+            for (expb, &coeffb) in &rhs.terms {
+                let len = expa.len().max(expb.len());
+                let mut exp = vec![0; len];
+                for (k, e) in exp.iter_mut().enumerate() {
+                    *e = expa.get(k).copied().unwrap_or(0) + expb.get(k).copied().unwrap_or(0);
+                }
+                *terms.entry(trim(exp)).or_insert(0) += coeffa * coeffb;
+            }
+        }
+        terms.retain(|_, &mut coeff| coeff != 0);
+        Poly { terms }
+    }
+}
+
+/// Build a `Monomial` from a dense exponent vector, inverse of `exp_vec`.
+fn monomial_from_exp(exp: &[usize]) -> Monomial {
+    let powers = exp
+        .iter()
+        .enumerate()
+        .filter(|&(_, &p)| p != 0)
+        .map(|(i, &p)| (i, p))
+        .collect();
+    Monomial { powers }
+}
+
+/// The dominant monomial `x_0^{n-1} x_1^{n-2} ... x_{n-2}^1` for the long
+/// permutation of length `n`.
+fn dominant_poly(n: usize) -> Poly {
+    let exp = (0..n.saturating_sub(1)).map(|i| n - 1 - i).collect();
+    Poly::term(exp, 1)
+}
+
+/// Apply the Newton divided-difference operator `∂_i` to `f`:
+/// `∂_i(f) = (f - s_i·f) / (x_i - x_{i+1})`, where `s_i` swaps the exponents
+/// of `x_i` and `x_{i+1}` in every monomial. The division is exact because
+/// each term's numerator is antisymmetric in those two variables; it is
+/// computed directly via the identity
+/// `(x^a y^b - x^b y^a)/(x - y) = sign(a - b) * sum_{k=lo}^{hi-1} x^k y^{lo+hi-1-k}`
+/// with `lo = min(a, b)`, `hi = max(a, b)`.
+fn divided_difference(f: &Poly, i: usize) -> Poly {
+    let mut terms: BTreeMap<Vec<usize>, i64> = BTreeMap::new();
+    for (exp, coeff) in f.terms() {
+        let a = exp.get(i).copied().unwrap_or(0);
+        let b = exp.get(i + 1).copied().unwrap_or(0);
+        if a == b {
+            continue;
+        }
+        let (lo, hi, sign) = if a > b { (b, a, 1) } else { (a, b, -1) };
+        for k in lo..hi {
+            let mut term_exp = exp.to_vec();
+            if term_exp.len() <= i + 1 {
+                term_exp.resize(i + 2, 0);
+            }
+            term_exp[i] = k;
+            term_exp[i + 1] = lo + hi - 1 - k;
+            *terms.entry(trim(term_exp)).or_insert(0) += sign * coeff;
+        }
+    }
+    terms.retain(|_, &mut coeff| coeff != 0);
+    Poly { terms }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dream::ReducedDreams;
+
+    /// All permutations of `0..n`, via Heap's algorithm.
+    fn permutations(n: usize) -> Vec<Vec<usize>> {
+        fn heap(k: usize, v: &mut Vec<usize>, res: &mut Vec<Vec<usize>>) {
+            if k == 1 {
+                res.push(v.clone());
+                return;
+            }
+            for i in 0..k {
+                heap(k - 1, v, res);
+                if k % 2 == 0 {
+                    v.swap(i, k - 1);
+                } else {
+                    v.swap(0, k - 1);
+                }
+            }
+        }
+        let mut res = Vec::new();
+        heap(n, &mut (0..n).collect(), &mut res);
+        res
+    }
+
+    #[test]
+    fn divided_differences_match_dreams() {
+        for n in 1..=5 {
+            for v in permutations(n) {
+                let perm = Perm::new(&v).unwrap();
+                let from_dreams = Schubert::from_dreams(&ReducedDreams::for_perm(&perm));
+                let from_dd = Schubert::from_divided_differences(&perm);
+                assert_eq!(
+                    Poly::from(&from_dreams),
+                    Poly::from(&from_dd),
+                    "mismatch for permutation {:?}",
+                    v
+                );
+            }
+        }
+    }
+}